@@ -1,8 +1,17 @@
-use rand::Rng;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use ratatui::widgets::ListState;
-use std::{error, vec};
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver};
+use std::{error, thread, vec};
 
-use crate::data::{self, Participant};
+use time::OffsetDateTime;
+
+use crate::config::Config;
+use crate::data::{self, ExportFormat, Participant};
 
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, Box<dyn error::Error>>;
@@ -25,33 +34,120 @@ pub struct App {
     pub position: f32,
     pub speed: f32,
     pub acceleration: f32,
+    pub target: usize,
     pub spin_winner: Option<Participant>,
+
+    /// Number of distinct winners a single raffle run should collect.
+    pub draw_count: usize,
+
+    // Reproducibility
+    /// Seed of the deterministic draw stream; printable for independent audits.
+    pub seed: u64,
+    /// Draw RNG seeded from [`App::seed`]; every spin advances the same stream.
+    pub rng: StdRng,
+
+    /// Loaded runtime configuration.
+    pub config: Config,
+
+    /// Receiver signalled whenever the participants file changes on disk.
+    pub reload_rx: Option<Receiver<()>>,
+    /// Filesystem watcher kept alive for the lifetime of the app.
+    pub watcher: Option<RecommendedWatcher>,
+
+    /// Optional live source of entrants drained each [`App::tick`].
+    pub entrant_rx: Option<Receiver<Participant>>,
 }
 
 impl Default for App {
     fn default() -> Self {
-        let tab_titles = vec!["Home".to_string(), "Participants".to_string()];
+        Self::with_seed(None)
+    }
+}
 
-        let participants = data::read_participants_from_file().expect("Failed to read file");
+impl App {
+    /// Constructs a new instance of [`App`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs an [`App`], either re-using a supplied draw seed or generating
+    /// and recording a fresh one.
+    ///
+    /// The seed plus the participant order fully determines the winner sequence,
+    /// so a raffle can be re-run and verified by anyone holding both.
+    pub fn with_seed(seed: Option<u64>) -> Self {
+        let config = Config::load();
+
+        let participants = data::read_participants_from_file(&config.participants_file)
+            .expect("Failed to read file");
+
+        let seed = seed.unwrap_or_else(rand::random);
 
         Self {
             running: true,
-            tabs: StatefulTabs::new(tab_titles),
+            tabs: StatefulTabs::new(config.tabs.clone()),
             all_participants: StatefulList::new(participants),
             all_winners: Vec::new(),
             is_spinning: false,
             position: 0.0,
             speed: 0.0,
             acceleration: 0.0,
+            target: 0,
             spin_winner: None,
+            draw_count: 1,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            config,
+            reload_rx: None,
+            watcher: None,
+            entrant_rx: None,
         }
     }
-}
 
-impl App {
-    /// Constructs a new instance of [`App`].
-    pub fn new() -> Self {
-        Self::default()
+    /// Spawns a producer thread that reads newline-delimited names from stdin
+    /// and feeds them in as live entrants.
+    ///
+    /// An external registration process (web form, bot) can pipe names into the
+    /// running TUI this way.
+    pub fn ingest_stdin(&mut self) {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let stdin = io::stdin();
+            for line in stdin.lock().lines() {
+                let Ok(line) = line else { break };
+                let name = line.trim();
+                if name.is_empty() {
+                    continue;
+                }
+                if tx.send(Participant::new(name.to_string())).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.entrant_rx = Some(rx);
+    }
+
+    /// Starts watching the participants file so edits made while the app runs
+    /// are picked up on the next [`App::tick`] that is not mid-spin (see
+    /// [`poll_reload`](Self::poll_reload)).
+    pub fn watch_participants(&mut self) -> AppResult<()> {
+        let (tx, rx) = mpsc::channel();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = tx.send(());
+            }
+        })?;
+        watcher.watch(
+            Path::new(&self.config.participants_file),
+            RecursiveMode::NonRecursive,
+        )?;
+
+        self.watcher = Some(watcher);
+        self.reload_rx = Some(rx);
+        Ok(())
     }
 
     /// Set running to false to quit the application
@@ -61,9 +157,94 @@ impl App {
 
     /// Handles the tick event of the terminal
     pub fn tick(&mut self) {
+        self.poll_reload();
+        self.poll_entrants();
         self.spin_round()
     }
 
+    /// Drains any live entrants received since the last tick and appends them to
+    /// the pool, skipping names that have already been drawn as winners or that
+    /// are already in the pool.
+    ///
+    /// Draining is deferred while a spin is in progress so the pool size — and
+    /// with it the reel modulus — stays fixed for the duration of an animation,
+    /// preserving the pre-selected target and the seed→winner guarantee.
+    pub fn poll_entrants(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        let Some(rx) = &self.entrant_rx else {
+            return;
+        };
+
+        let mut incoming = Vec::new();
+        while let Ok(participant) = rx.try_recv() {
+            incoming.push(participant);
+        }
+
+        for participant in incoming {
+            let name = participant.name.as_str();
+            let known = self
+                .all_participants
+                .items
+                .iter()
+                .any(|p| p.name == name)
+                || self.all_winners.iter().any(|w| w.name == name);
+            if known {
+                continue;
+            }
+            self.all_participants.items.push(participant);
+        }
+    }
+
+    /// Reloads participants from disk when the watcher has reported a change.
+    ///
+    /// Reloads are deferred while a spin is in progress so the animation is not
+    /// disturbed mid-draw.
+    pub fn poll_reload(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        let Some(rx) = &self.reload_rx else {
+            return;
+        };
+
+        let mut pending = false;
+        while rx.try_recv().is_ok() {
+            pending = true;
+        }
+
+        if pending {
+            self.reload_participants();
+        }
+    }
+
+    /// Re-reads the participants file and merges newcomers into the pool by
+    /// name, leaving existing entries — their drawn flags and any live sign-ups
+    /// streamed in via [`poll_entrants`](Self::poll_entrants) — untouched and
+    /// skipping names that have already been drawn as winners.
+    fn reload_participants(&mut self) {
+        let Ok(fresh) = data::read_participants_from_file(&self.config.participants_file) else {
+            return;
+        };
+
+        let drawn: HashSet<&str> = self.all_winners.iter().map(|p| p.name.as_str()).collect();
+        let known: HashSet<&str> = self
+            .all_participants
+            .items
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        let newcomers: Vec<Participant> = fresh
+            .into_iter()
+            .filter(|p| !drawn.contains(p.name.as_str()) && !known.contains(p.name.as_str()))
+            .collect();
+
+        self.all_participants.items.extend(newcomers);
+    }
+
     pub fn start_spin(&mut self) {
         if self.all_participants.items.is_empty() {
             return;
@@ -71,18 +252,91 @@ impl App {
 
         let participant_count = self.all_participants.items.len() as f32;
 
-        let min_acceleration = 0.5 / participant_count;
-        let max_acceleration = 0.9 / participant_count;
-
-        let mut rng = rand::thread_rng();
+        let min_acceleration = self.config.spinner.min_acceleration / participant_count;
+        let max_acceleration = self.config.spinner.max_acceleration / participant_count;
 
-        self.acceleration = rng.gen_range(min_acceleration..max_acceleration);
+        self.acceleration = self.rng.gen_range(min_acceleration..max_acceleration);
         self.position = 0.0;
-        self.speed = 3.0;
+        self.speed = self.config.spinner.speed;
+        self.target = Self::pick_weighted(&self.all_participants.items, &mut self.rng);
+        self.aim_at_target();
         self.spin_winner = None;
         self.is_spinning = true;
     }
 
+    /// Pre-selects a winner with Walker's alias method, biasing the draw toward
+    /// higher-weighted participants while keeping sampling O(1).
+    ///
+    /// Weights are scaled so their sum equals `n`; indices are then partitioned
+    /// into `small` (scaled < 1) and `large` (>= 1) worklists and paired off to
+    /// build the `prob`/`alias` tables consumed below.
+    fn pick_weighted(items: &[Participant], rng: &mut StdRng) -> usize {
+        let n = items.len();
+
+        let total: f32 = items.iter().map(|p| p.weight).sum();
+        if total <= 0.0 {
+            return rng.gen_range(0..n);
+        }
+
+        let scale = n as f32 / total;
+        let mut scaled: Vec<f32> = items.iter().map(|p| p.weight * scale).collect();
+
+        let mut prob = vec![1.0f32; n];
+        let mut alias = vec![0usize; n];
+
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for (i, &p) in scaled.iter().enumerate() {
+            if p < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+        // Leftover indices sit at 1.0 (up to rounding) and keep their default prob.
+
+        let i = rng.gen_range(0..n);
+        let u: f32 = rng.gen_range(0.0..1.0);
+        if u < prob[i] {
+            i
+        } else {
+            alias[i]
+        }
+    }
+
+    /// Offsets the reel so that the existing decaying animation comes to rest on
+    /// [`App::target`]. The total travel of a spin is fixed once `speed` and
+    /// `acceleration` are chosen, so we rotate the starting index backward by
+    /// that travel instead of tampering with the animation itself.
+    fn aim_at_target(&mut self) {
+        let n = self.all_participants.items.len();
+
+        let mut speed = self.speed;
+        let mut position = 0.0f32;
+        let mut travel = 0usize;
+        while speed > 0.1 {
+            position += speed;
+            let i = position.floor() as usize;
+            travel += i;
+            position -= i as f32;
+            speed *= 1.0 - self.acceleration;
+        }
+
+        let start = (self.target as isize - travel as isize).rem_euclid(n as isize) as usize;
+        self.all_participants.state.select(Some(start));
+    }
+
     pub fn apply_acceleration(&mut self) {
         self.position += self.speed;
         let i = self.position.floor() as usize;
@@ -103,11 +357,20 @@ impl App {
 
         if let Some(winner) = &mut self.all_participants.get_selected() {
             winner.is_winner = true;
+            winner.drawn_at = Some(OffsetDateTime::now_utc());
 
             self.spin_winner = Some(winner.clone());
             self.all_winners.push(winner.clone());
 
-            self.stop_spin();
+            // Draw without replacement so a winner cannot be pulled twice.
+            self.all_participants.remove();
+
+            // Keep re-arming until the requested number of winners is reached.
+            if self.all_winners.len() < self.draw_count && !self.all_participants.items.is_empty() {
+                self.start_spin();
+            } else {
+                self.stop_spin();
+            }
         }
     }
 
@@ -120,6 +383,25 @@ impl App {
         self.speed = 0.0;
         self.spin_winner = None;
     }
+
+    /// Sets how many distinct winners the next run should draw (at least one).
+    pub fn set_draw_count(&mut self, count: usize) {
+        self.draw_count = count.max(1);
+    }
+
+    /// Progress label for the Home tab, e.g. `"2 of 5 drawn"`.
+    pub fn draw_progress(&self) -> String {
+        format!("{} of {} drawn", self.all_winners.len(), self.draw_count)
+    }
+
+    /// Writes the ordered, timestamped winner log to `path` as CSV or JSON.
+    pub fn export_winners<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        format: ExportFormat,
+    ) -> AppResult<()> {
+        data::export_winners(&self.all_winners, self.seed, path, format)
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,149 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{env, fs};
+
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer};
+
+use crate::app::AppResult;
+
+/// Name of the configuration file looked up on startup.
+const CONFIG_FILE: &str = "raffle.toml";
+
+/// Runtime configuration, loaded from `raffle.toml` or defaulted when absent.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Path of the newline-delimited participants file.
+    pub participants_file: String,
+    /// Tab titles shown along the top bar.
+    pub tabs: Vec<String>,
+    /// Spinner animation tuning.
+    pub spinner: SpinnerConfig,
+    /// Interface colors.
+    pub theme: ThemeConfig,
+}
+
+/// Spinner animation tuning.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpinnerConfig {
+    /// Starting reel speed.
+    pub speed: f32,
+    /// Lower bound of the per-spin acceleration, scaled by the participant count.
+    pub min_acceleration: f32,
+    /// Upper bound of the per-spin acceleration, scaled by the participant count.
+    pub max_acceleration: f32,
+}
+
+/// Interface colors.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Color of the currently highlighted entry.
+    #[serde(deserialize_with = "deserialize_color")]
+    pub highlight: Color,
+    /// Color used to mark drawn winners.
+    #[serde(deserialize_with = "deserialize_color")]
+    pub winner: Color,
+    /// Color of borders and inactive text.
+    #[serde(deserialize_with = "deserialize_color")]
+    pub border: Color,
+}
+
+/// Deserializes a [`Color`] from its textual name (e.g. `"yellow"`, `"#ff8800"`)
+/// via [`Color`]'s `FromStr`, so the config never relies on ratatui's optional
+/// `serde` feature being enabled.
+fn deserialize_color<'de, D>(deserializer: D) -> Result<Color, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    Color::from_str(&raw).map_err(serde::de::Error::custom)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            participants_file: "participants.txt".to_string(),
+            tabs: vec!["Home".to_string(), "Participants".to_string()],
+            spinner: SpinnerConfig::default(),
+            theme: ThemeConfig::default(),
+        }
+    }
+}
+
+impl Default for SpinnerConfig {
+    fn default() -> Self {
+        Self {
+            speed: 3.0,
+            min_acceleration: 0.5,
+            max_acceleration: 0.9,
+        }
+    }
+}
+
+impl SpinnerConfig {
+    /// Restores the default acceleration bounds when a config leaves them empty
+    /// or reversed, so sampling the `min..max` range can never panic.
+    fn sanitize(&mut self) {
+        let ordered = self.min_acceleration.is_finite()
+            && self.max_acceleration.is_finite()
+            && self.min_acceleration < self.max_acceleration;
+        if !ordered {
+            let default = Self::default();
+            self.min_acceleration = default.min_acceleration;
+            self.max_acceleration = default.max_acceleration;
+        }
+    }
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            highlight: Color::Yellow,
+            winner: Color::Green,
+            border: Color::White,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration, preferring a `raffle.toml` in the working
+    /// directory and otherwise the XDG config directory, falling back to the
+    /// built-in defaults when none is found.
+    pub fn load() -> Self {
+        let mut config = match Self::resolve_path().and_then(Self::read) {
+            Some(config) => config,
+            None => Self::default(),
+        };
+        config.spinner.sanitize();
+        config
+    }
+
+    /// Returns the first existing config path from the working directory then
+    /// the XDG config lookup.
+    fn resolve_path() -> Option<PathBuf> {
+        let working_dir = PathBuf::from(CONFIG_FILE);
+        if working_dir.exists() {
+            return Some(working_dir);
+        }
+
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+        let xdg = config_home.join("raffle").join(CONFIG_FILE);
+        xdg.exists().then_some(xdg)
+    }
+
+    /// Reads and parses a config file, discarding it on any I/O or parse error.
+    fn read(path: PathBuf) -> Option<Config> {
+        Self::try_read(&path).ok()
+    }
+
+    fn try_read(path: &PathBuf) -> AppResult<Config> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+}
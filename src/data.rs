@@ -0,0 +1,168 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::app::AppResult;
+
+/// A single raffle entrant.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    /// Display name of the entrant.
+    pub name: String,
+    /// Relative odds of being drawn; higher means more likely.
+    pub weight: f32,
+    /// Set once the participant has been drawn as a winner.
+    pub is_winner: bool,
+    /// Instant the participant was drawn, set alongside `is_winner`.
+    pub drawn_at: Option<OffsetDateTime>,
+}
+
+impl Participant {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            weight: 1.0,
+            is_winner: false,
+            drawn_at: None,
+        }
+    }
+
+    pub fn with_weight(name: String, weight: f32) -> Self {
+        Self {
+            name,
+            weight,
+            is_winner: false,
+            drawn_at: None,
+        }
+    }
+}
+
+/// Output format for an exported winner log.
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// One ordered, timestamped winner row in an export.
+#[derive(Debug, Serialize)]
+struct WinnerRecord<'a> {
+    /// Draw order, starting at one.
+    order: usize,
+    /// Winner name.
+    name: &'a str,
+    /// Draw timestamp, RFC 3339, or empty when unset.
+    drawn_at: String,
+}
+
+impl<'a> WinnerRecord<'a> {
+    fn new(order: usize, participant: &'a Participant) -> Self {
+        let drawn_at = participant
+            .drawn_at
+            .and_then(|ts| ts.format(&Rfc3339).ok())
+            .unwrap_or_default();
+
+        Self {
+            order,
+            name: &participant.name,
+            drawn_at,
+        }
+    }
+}
+
+/// An exported winner log, pairing the draw `seed` with its ordered rows so the
+/// result can be independently re-run and audited.
+#[derive(Debug, Serialize)]
+struct WinnerLog<'a> {
+    /// Seed that produced this draw sequence.
+    seed: u64,
+    /// Ordered, timestamped winner rows.
+    winners: Vec<WinnerRecord<'a>>,
+}
+
+/// Escapes a CSV field per RFC 4180: a field containing a comma, quote, CR or
+/// LF is wrapped in double quotes, with embedded quotes doubled.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes `winners` — name, draw order and timestamp — to `path` as CSV or
+/// JSON, recording `seed` so the draw can be reproduced from the export together
+/// with the participant list it was drawn from.
+pub fn export_winners<P: AsRef<Path>>(
+    winners: &[Participant],
+    seed: u64,
+    path: P,
+    format: ExportFormat,
+) -> AppResult<()> {
+    let log = WinnerLog {
+        seed,
+        winners: winners
+            .iter()
+            .enumerate()
+            .map(|(i, w)| WinnerRecord::new(i + 1, w))
+            .collect(),
+    };
+
+    let contents = match format {
+        ExportFormat::Csv => {
+            let mut out = format!("# seed={seed}\norder,name,drawn_at\n");
+            for record in &log.winners {
+                writeln!(
+                    out,
+                    "{},{},{}",
+                    record.order,
+                    csv_field(record.name),
+                    csv_field(&record.drawn_at),
+                )?;
+            }
+            out
+        }
+        ExportFormat::Json => serde_json::to_string_pretty(&log)?,
+    };
+
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Parses a single `name [weight]` line into a [`Participant`].
+///
+/// A trailing numeric token is read as the entry's weight; anything else is
+/// treated as part of the name and defaults to a weight of `1.0`. A weight that
+/// is not finite and strictly positive is rejected the same way, so a typo in
+/// the data file cannot skew the alias tables.
+fn parse_participant(line: &str) -> Participant {
+    let line = line.trim();
+
+    if let Some((name, weight)) = line.rsplit_once(char::is_whitespace) {
+        if let Ok(weight) = weight.parse::<f32>() {
+            if weight.is_finite() && weight > 0.0 {
+                return Participant::with_weight(name.trim().to_string(), weight);
+            }
+        }
+    }
+
+    Participant::new(line.to_string())
+}
+
+/// Reads the participants file at `path`, one entrant per line.
+pub fn read_participants_from_file<P: AsRef<Path>>(path: P) -> AppResult<Vec<Participant>> {
+    let contents = fs::read_to_string(path)?;
+
+    let participants = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_participant)
+        .collect();
+
+    Ok(participants)
+}